@@ -6,17 +6,17 @@ use sr_primitives::traits::{
 	Block as BlockT, Header as HeaderT, ProvideRuntimeApi, UniqueSaturatedInto,
 };
 use hex;
-use std::mem::transmute;
 use client::{blockchain::HeaderBackend, backend::AuxStore};
 use codec::{Encode, Decode};
 use consensus_poc::PocAlgorithm;
-use consensus_poc_primitives::{Seal as RawSeal, DifficultyApi,NonceData as RawNonceData};
+use consensus_poc_primitives::{Seal as RawSeal, DifficultyApi, TimestampApi,NonceData as RawNonceData, PlotProof, ScoopDerivation, scoop_number, SCOOPS_PER_NONCE};
 use conjugatepoc_primitives::{Difficulty, AlgorithmApi, DAY_HEIGHT, HOUR_HEIGHT,HASH_SIZE,NONCE_SIZE,HASH_CAP,MESSAGE_SIZE};
 use lru_cache::LruCache;
 use rand::{SeedableRng, thread_rng, rngs::SmallRng};
 use log::*;
 mod shabal256;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::fs;
 use std::fs::OpenOptions;
 use std::io::{Read, Seek, SeekFrom};
@@ -168,19 +168,100 @@ fn key_hash<B, C>(
 
 pub struct RandomXAlgorithm<C> {
 	client: Arc<C>,
+	scoop_derivation: ScoopDerivation,
+	plot_proof: Option<PlotProof>,
+	plot_file: Option<PathBuf>,
+	// Whether the last mining round found no nonce.
+	exhausted: AtomicBool,
 }
 
 impl<C> RandomXAlgorithm<C> {
 	pub fn new(client: Arc<C>) -> Self {
-		Self { client }
+		Self {
+			client,
+			scoop_derivation: ScoopDerivation::default(),
+			plot_proof: None,
+			plot_file: None,
+			exhausted: AtomicBool::new(false),
+		}
+	}
+
+	/// Mine on the plot file at `plot_file`. Without one, which is enough to
+	/// verify blocks, no nonce is ever mined.
+	pub fn with_plot_file(mut self, plot_file: impl Into<PathBuf>) -> Self {
+		self.plot_file = Some(plot_file.into());
+		self
+	}
+
+	/// Attach `plot_proof`, the proof that the plot mined on is committed to,
+	/// to mined nonces, as capacity-permissioned chains require. See
+	/// `consensus_poc::MerklePlotCommitment::proof`.
+	pub fn with_plot_proof(mut self, plot_proof: PlotProof) -> Self {
+		self.plot_proof = Some(plot_proof);
+		self
+	}
+
+	/// Read the one nonce mined of the plot file, if any, against
+	/// `generation_sig` for the block at `height`.
+	fn mine_plot(&self, height: u64, generation_sig: H256) -> Result<Option<RawNonceData>, String> {
+		let plotfile = match &self.plot_file {
+			Some(plotfile) if plotfile.is_file() => plotfile,
+			_ => return Ok(None),
+		};
+		let plot = match PlotReader::open(plotfile) {
+			Ok(plot) => plot,
+			Err(e) => {
+				warn!("Not mining on plot file {:?}: {}", plotfile, e);
+				return Ok(None);
+			},
+		};
+		let account_id = plot.account_id();
+		let nonce = 1u64;
+		if !plot.contains(nonce) {
+			warn!("Not mining on plot file {:?}: nonce {} not in the plot", plotfile, nonce);
+			return Ok(None);
+		}
+		let gensig = decode_gensig(&generation_sig);
+		let scoop = calculate_scoop(height, &gensig, account_id, self.scoop_derivation);
+		let scoopdata = plot.read_scoop(nonce, scoop)?;
+		debug!("Scoop {} of nonce {}: {} {}", scoop, nonce,
+			hex::encode(&scoopdata[0..32]), hex::encode(&scoopdata[32..64]));
+		let (deadline, best_offset) = find_best_deadline_rust(&scoopdata[..], 1, &gensig);
+		debug!("Best deadline at height {}: {}", height, deadline);
+		let nonce = nonce + best_offset;
+
+		let noncedata = NonceData{
+			height,
+			deadline,
+			nonce,
+			reader_task_processed: true,
+			account_id,
+			generation_sig,
+		};
+		Ok(Some(RawNonceData {
+			account_id: account_id_bytes(account_id),
+			nonce,
+			deadline,
+			data: noncedata.encode(),
+			signature: Vec::new(),
+			plot_proof: self.plot_proof.clone(),
+		}))
+	}
+
+	/// Derive scoops as `scoop_derivation` tells, for both mining and
+	/// verification. Account-independent, as in PoC2, by default.
+	pub fn with_scoop_derivation(mut self, scoop_derivation: ScoopDerivation) -> Self {
+		self.scoop_derivation = scoop_derivation;
+		self
 	}
 }
 
 impl<B: BlockT<Hash=H256>, C> PocAlgorithm<B> for RandomXAlgorithm<C> where
 	C: HeaderBackend<B> + AuxStore + ProvideRuntimeApi,
-	C::Api: DifficultyApi<B, Difficulty> + AlgorithmApi<B>,
+	C::Api: DifficultyApi<B, Difficulty> + AlgorithmApi<B> + TimestampApi<B, u64>,
 {
 	type Difficulty = Difficulty;
+	type BaseTarget = Difficulty;
 
 	fn difficulty(&self, parent: &BlockId<B>) -> Result<Difficulty, String> {
 		let difficulty = self.client.runtime_api().difficulty(parent)
@@ -189,6 +270,36 @@ impl<B: BlockT<Hash=H256>, C> PocAlgorithm<B> for RandomXAlgorithm<C> where
 		difficulty
 	}
 
+	fn timestamp(&self, block: &BlockId<B>) -> Result<Option<u64>, String> {
+		self.client.runtime_api().timestamp(block)
+			.map(Some)
+			.map_err(|e| format!("Fetching timestamp from runtime failed: {:?}", e))
+	}
+
+	fn block_timestamp(&self, block: &B) -> Result<Option<u64>, String> {
+		for extrinsic in block.extrinsics() {
+			let extrinsic = conjugatepoc_runtime::UncheckedExtrinsic::decode(&mut &extrinsic.encode()[..])
+				.map_err(|e| format!("Decoding extrinsic failed: {:?}", e))?;
+			if let conjugatepoc_runtime::Call::Timestamp(conjugatepoc_runtime::TimestampCall::set(now)) = extrinsic.function {
+				return Ok(Some(now))
+			}
+		}
+
+		Ok(None)
+	}
+
+	fn scan_exhausted(&self, _parent: &BlockId<B>, _generation_sig: &H256) -> Result<bool, String> {
+		// every round reads the one nonce of the plot, so a round finding
+		// nothing leaves nothing to scan
+		Ok(self.exhausted.load(Ordering::Relaxed))
+	}
+
+	fn proven_account(&self, _block: &B::Header, nonce_data: &RawNonceData) -> Result<[u8; 32], String> {
+		let proof = NonceData::decode(&mut &nonce_data.data[..])
+			.map_err(|e| format!("Decoding the PoC2 proof of the nonce data failed: {:?}", e))?;
+		Ok(account_id_bytes(proof.account_id))
+	}
+
 	fn getmineinfo(&self){
 		let mut io = IoHandler::new();
 		io.add_method("get_mine_info",|| {
@@ -243,22 +354,30 @@ impl<B: BlockT<Hash=H256>, C> PocAlgorithm<B> for RandomXAlgorithm<C> where
 		&self,
 		parent: &BlockId<B>,
 		pre_hash: &H256,
+		expected_generation_sig: H256,
 		nonce_data: &RawNonceData,
 		baseTarget: Difficulty,
 	) -> Result<bool, String> {
 		assert_eq!(self.client.runtime_api().identifier(parent)
 			.map_err(|e| format!("Fetching identifier from runtime failed: {:?}", e))?,conjugatepoc_primitives::ALGORITHM_IDENTIFIER);
-		let nonce_data = match NonceData::decode(&mut &nonce_data[..]){
-			Ok(nonce_data) => nonce_data,
+		let proof = match NonceData::decode(&mut &nonce_data.data[..]){
+			Ok(proof) => proof,
 			Err(_) => return Ok(false),
 		};
+		if !binds_proof(nonce_data, &proof, child_number(&self.client, parent)?) {
+			return Ok(false);
+		}
+		let nonce_data = proof;
 		let account_id = nonce_data.account_id;
 		let height = nonce_data.height;
 		let nonce = nonce_data.nonce;
 		let generation_sig = nonce_data.generation_sig;
+		if generation_sig != expected_generation_sig {
+			return Ok(false);
+		}
 		let submit_deadline = nonce_data.deadline;
 		let gensig = decode_gensig(&generation_sig);
-		let scoop = calculate_scoop(height,&gensig);
+		let scoop = calculate_scoop(height, &gensig, account_id, self.scoop_derivation);
 		let mut cache = vec![0u8; 262144];
 		noncegen_rust(&mut cache[..], account_id, nonce, 1);
 		let address = 64 * scoop as usize;
@@ -281,8 +400,9 @@ impl<B: BlockT<Hash=H256>, C> PocAlgorithm<B> for RandomXAlgorithm<C> where
 	fn poc_mine(
 		&self,
 		parent: &BlockId<B>,
+		_pre_hash: &H256,
 		generation_sig: H256,
-		baseTarget: U256,
+		_baseTarget: U256,
 	) -> Result<Option<RawNonceData>,String> {
 		// PoC 挖矿，从钱包提交过来的rpc请求，submit_nonce方法，包括参数 SubmitNonce 结构体中的参数。
 			// pub accout_id: u64, 钱包账号id，也是plot_id
@@ -298,52 +418,9 @@ impl<B: BlockT<Hash=H256>, C> PocAlgorithm<B> for RandomXAlgorithm<C> where
 
 		// 查看deadline时间是否流逝完，是否可以出块
 
-		let height : u64 = 1;
-		// todo: get the height from the parent
-		let filename = "/Users/mac/projects/conjugate-poc/10790126960500947771_1_4096";
-		let plotfile = Path::new(&filename);
-		if !plotfile.is_file() {
-			return Ok(None);
-		}
-		let name = plotfile.file_name().unwrap().to_str().unwrap();
-		let parts: Vec<&str> = name.split("_").collect();
-		let account_id = parts[0].parse::<u64>().unwrap();
-		let start_nonce = parts[1].parse::<u64>().unwrap();
-		let nonces = parts[2].parse::<u64>().unwrap();
-		let size = fs::metadata(plotfile).unwrap().len();
-		let exp_size = nonces * 4096 * 64;
-		if size != exp_size as u64 {
-			println!("expected plot size {} but got {}",exp_size,size);
-			return Ok(None);
-		}
-		let nonce = 1u64;
-		if !(nonce >= start_nonce && nonce < start_nonce + nonces){
-			println!("File does't contain requested nonce");
-			return Ok(None);
-		}
-		let gensig = decode_gensig(&generation_sig);
-		let scoop = calculate_scoop(height,&gensig);
-		let address = scoop as u64 * 64 * 4096 + (nonce - start_nonce) * 64;
-		let mut file = OpenOptions::new().read(true).open(plotfile).unwrap();
-		let mut scoopdata = vec![0u8; 64];
-		file.seek(SeekFrom::Start(address)).unwrap();
-		file.read_exact(&mut scoopdata[0..64]).unwrap();
-		println!("Hash 1:              : {:?}",&hex::encode(&scoopdata[0..32]));
-        println!("Hash 2:              : {:?}",&hex::encode(&scoopdata[32..64]));
-		let (deadline, best_offset) = find_best_deadline_rust(&scoopdata[..], 1, &gensig);
-		let deadline_adj = deadline / baseTarget.as_u64();
-		println!("Deadline 2 (raw)     : {}", deadline);
-		println!("Deadline 2 (adj)     : {}", deadline_adj);
-
-		let noncedata = NonceData{
-			height,
-			deadline,
-			nonce: best_offset,
-			reader_task_processed: true,
-			account_id,
-			generation_sig,
-		};
-		return Ok(Some(noncedata.encode()))
+		let found = self.mine_plot(child_number(&self.client, parent)?, generation_sig)?;
+		self.exhausted.store(found.is_none(), Ordering::Relaxed);
+		Ok(found)
 		// if deadline_adj <= targetDeadline {
 		// 	let noncedata = NonceData{
 		// 		height,
@@ -390,6 +467,156 @@ impl<B: BlockT<Hash=H256>, C> PocAlgorithm<B> for RandomXAlgorithm<C> where
 	}
 }
 
+/// Whether the account, nonce and deadline carried by `nonce_data` for the
+/// consensus engine are the ones `proof`, decoded from its data, establishes.
+/// The engine keys nonce reuse, plot commitments, deadline bounds and mining
+/// statistics on them, so `poc_verify` rejects nonce data where they differ.
+/// The proof must also be mined for `number`, the block being verified, as
+/// its scoop is derived from the height it carries.
+fn binds_proof(nonce_data: &RawNonceData, proof: &NonceData, number: u64) -> bool {
+	nonce_data.account_id == account_id_bytes(proof.account_id)
+		&& nonce_data.nonce == proof.nonce
+		&& nonce_data.deadline == proof.deadline
+		&& proof.height == number
+}
+
+/// Number of the block built on `parent`.
+fn child_number<B, C>(client: &C, parent: &BlockId<B>) -> Result<u64, String> where
+	B: BlockT<Hash=H256>,
+	C: HeaderBackend<B>,
+{
+	let parent_header = client.header(parent.clone())
+		.map_err(|e| format!("Client execution error: {:?}", e))?
+		.ok_or("Parent header not found")?;
+	Ok(UniqueSaturatedInto::<u64>::unique_saturated_into(*parent_header.number()) + 1)
+}
+
+/// Numeric account id in the fixed-size form carried by the PoC seal.
+pub fn account_id_bytes(account_id: u64) -> [u8; 32] {
+	let mut bytes = [0u8; 32];
+	bytes[..8].copy_from_slice(&account_id.to_le_bytes());
+	bytes
+}
+
+/// Number of nonces `PlotReader::verify_plot` samples.
+pub const PLOT_SAMPLE_NONCES: u64 = 16;
+
+/// Scoops of each sampled nonce `PlotReader::verify_plot` checks: the first,
+/// the middle and the last one.
+pub const PLOT_SAMPLE_SCOOPS: [u32; 3] = [0, SCOOPS_PER_NONCE / 2, SCOOPS_PER_NONCE - 1];
+
+/// Reader of a PoC2 plot file, named `<account id>_<start nonce>_<nonces>`
+/// and laid out scoop by scoop: scoop `s` of every nonce, in nonce order,
+/// then scoop `s + 1`.
+pub struct PlotReader {
+	path: PathBuf,
+	account_id: u64,
+	start_nonce: u64,
+	nonces: u64,
+}
+
+impl PlotReader {
+	/// Open the plot file at `path`. Fails if its name does not follow the
+	/// naming scheme or its size does not match the number of nonces.
+	pub fn open(path: &Path) -> Result<Self, String> {
+		let name = path.file_name().and_then(|name| name.to_str())
+			.ok_or_else(|| format!("plot file {:?} has no name", path))?;
+		let parts = name.split('_')
+			.map(|part| part.parse::<u64>())
+			.collect::<std::result::Result<Vec<_>, _>>()
+			.map_err(|e| format!("plot file name {} is not <account>_<start>_<nonces>: {}", name, e))?;
+		let (account_id, start_nonce, nonces) = match parts[..] {
+			[account_id, start_nonce, nonces] => (account_id, start_nonce, nonces),
+			_ => return Err(format!("plot file name {} is not <account>_<start>_<nonces>", name)),
+		};
+
+		let size = fs::metadata(path).map_err(|e| format!("reading metadata of {:?} failed: {}", path, e))?.len();
+		let expected = nonces.checked_mul(NONCE_SIZE as u64)
+			.ok_or_else(|| format!("plot file {} claims too many nonces", name))?;
+		if size != expected {
+			return Err(format!("expected plot size {} but got {}", expected, size))
+		}
+
+		Ok(PlotReader { path: path.to_path_buf(), account_id, start_nonce, nonces })
+	}
+
+	/// Numeric id of the account the plot belongs to.
+	pub fn account_id(&self) -> u64 {
+		self.account_id
+	}
+
+	/// Whether the plot holds `nonce`.
+	pub fn contains(&self, nonce: u64) -> bool {
+		nonce >= self.start_nonce && nonce - self.start_nonce < self.nonces
+	}
+
+	/// Read `scoop` of `nonce` as stored in the plot.
+	pub fn read_scoop(&self, nonce: u64, scoop: u32) -> Result<[u8; SCOOP_SIZE], String> {
+		if !self.contains(nonce) || scoop >= SCOOPS_PER_NONCE {
+			return Err(format!("plot {:?} has no scoop {} of nonce {}", self.path, scoop, nonce))
+		}
+
+		let address = (u64::from(scoop) * self.nonces + nonce - self.start_nonce) * SCOOP_SIZE as u64;
+		let mut scoop_data = [0u8; SCOOP_SIZE];
+		let mut file = OpenOptions::new().read(true).open(&self.path)
+			.map_err(|e| format!("opening plot {:?} failed: {}", self.path, e))?;
+		file.seek(SeekFrom::Start(address))
+			.and_then(|_| file.read_exact(&mut scoop_data))
+			.map_err(|e| format!("reading scoop {} of nonce {} failed: {}", scoop, nonce, e))?;
+
+		Ok(scoop_data)
+	}
+
+	/// Check that the plot belongs to `account_id` and that it holds correct
+	/// data for the nonces from `nonce_range.0` up to, excluding,
+	/// `nonce_range.1`, before mining on it: a corrupt plot yields nonces
+	/// that always fail `poc_verify`. Regenerates `PLOT_SAMPLE_NONCES`
+	/// nonces, spread evenly over the range, and compares their
+	/// `PLOT_SAMPLE_SCOOPS` with the stored ones.
+	pub fn verify_plot(&self, account_id: &[u8; 32], nonce_range: (u64, u64)) -> Result<(), String> {
+		self.verify_plot_sampled(account_id, nonce_range, PLOT_SAMPLE_NONCES)
+	}
+
+	/// Check the plot like `verify_plot`, regenerating `samples` nonces.
+	pub fn verify_plot_sampled(
+		&self,
+		account_id: &[u8; 32],
+		nonce_range: (u64, u64),
+		samples: u64,
+	) -> Result<(), String> {
+		if *account_id != account_id_bytes(self.account_id) {
+			return Err(format!("plot {:?} belongs to account {}", self.path, self.account_id))
+		}
+		let (start, end) = nonce_range;
+		if start >= end || !self.contains(start) || !self.contains(end - 1) {
+			return Err(format!("plot {:?} does not hold nonces {} to {}", self.path, start, end))
+		}
+
+		let span = end - start;
+		let samples = samples.max(1).min(span);
+		let mut cache = vec![0u8; NONCE_SIZE];
+		for i in 0..samples {
+			// Spread evenly, first and last nonce included.
+			let nonce = if samples == 1 { start } else { start + i * (span - 1) / (samples - 1) };
+			noncegen_rust(&mut cache[..], self.account_id, nonce, 1);
+			for scoop in PLOT_SAMPLE_SCOOPS.iter() {
+				// PoC2 stores the second hash of a scoop with the mirror scoop.
+				let address = SCOOP_SIZE * *scoop as usize;
+				let mirror_address = SCOOP_SIZE * (SCOOPS_PER_NONCE - 1 - scoop) as usize;
+				let mut expected = [0u8; SCOOP_SIZE];
+				expected[..32].copy_from_slice(&cache[address..address + 32]);
+				expected[32..].copy_from_slice(&cache[mirror_address + 32..mirror_address + 64]);
+
+				if self.read_scoop(nonce, *scoop)?[..] != expected[..] {
+					return Err(format!("plot {:?} is corrupt at scoop {} of nonce {}", self.path, scoop, nonce))
+				}
+			}
+		}
+
+		Ok(())
+	}
+}
+
 pub fn find_best_deadline_rust(data: &[u8],number_of_nonces: u64,gensig: &[u8;32]) -> (u64,u64){
 	let mut best_deadline = std::u64::MAX;
 	let mut best_offset = 0;
@@ -407,15 +634,10 @@ pub fn decode_gensig(gensig: &H256) -> [u8;32] {
 	gensig_bytes[..].clone_from_slice(&hex::decode(gensig).unwrap());
 	gensig_bytes
 }
-pub fn calculate_scoop(height: u64, gensig: &[u8;32]) -> u32 {
-	let mut data: [u8;64] = [0;64];
-	let height_bytes: [u8;8] = unsafe {transmute(height.to_be())};
-	data[..32].clone_from_slice(gensig);
-	data[32..40].clone_from_slice(&height_bytes);
-	data[40] = 0x80;
+pub fn calculate_scoop(height: u64, gensig: &[u8;32], account_id: u64, derivation: ScoopDerivation) -> u32 {
+	let data = derivation.preimage(gensig, height, account_id);
 	let data = unsafe { std::mem::transmute::<&[u8; 64], &[u32; 16]>(&data) };
-	let new_gensig = &shabal256_hash_fast(&[], &data);
-	(u32::from(new_gensig[30] & 0x0F) << 8) | u32::from(new_gensig[31])
+	scoop_number(&shabal256_hash_fast(&[], &data))
 }
 
 pub fn noncegen_rust(cache: &mut [u8], numeric_id: u64, local_startnonce: u64, local_nonces: u64) {
@@ -529,4 +751,70 @@ mod tests {
 		let hash2 = compute.compute();
 		assert!(hash1 != hash2);
 	}
+
+	#[test]
+	fn nonce_data_must_carry_the_proven_values() {
+		let proof = NonceData {
+			height: 1,
+			deadline: 7,
+			nonce: 5,
+			reader_task_processed: true,
+			account_id: 3,
+			generation_sig: H256::repeat_byte(1),
+		};
+		let nonce_data = RawNonceData {
+			account_id: account_id_bytes(3),
+			nonce: 5,
+			deadline: 7,
+			data: proof.encode(),
+			..Default::default()
+		};
+		assert!(binds_proof(&nonce_data, &proof, 1));
+
+		// Replaying the proof under another account, nonce or deadline.
+		assert!(!binds_proof(&RawNonceData { account_id: account_id_bytes(4), ..nonce_data.clone() }, &proof, 1));
+		assert!(!binds_proof(&RawNonceData { nonce: 6, ..nonce_data.clone() }, &proof, 1));
+		assert!(!binds_proof(&RawNonceData { deadline: 1, ..nonce_data.clone() }, &proof, 1));
+
+		// Replaying the proof at another height.
+		assert!(!binds_proof(&nonce_data, &proof, 2));
+	}
+
+	#[test]
+	fn plot_is_verified_against_regenerated_nonces() {
+		let (account_id, start_nonce, nonces) = (10790126960500947771u64, 5u64, 3u64);
+		let dir = std::env::temp_dir().join(format!("poc-plot-{}", std::process::id()));
+		fs::create_dir_all(&dir).unwrap();
+		let path = dir.join(format!("{}_{}_{}", account_id, start_nonce, nonces));
+
+		// Lay the nonces out scoop by scoop, as PoC2 plotters do.
+		let mut cache = vec![0u8; NONCE_SIZE * nonces as usize];
+		noncegen_rust(&mut cache[..], account_id, start_nonce, nonces);
+		let mut plot = vec![0u8; cache.len()];
+		for scoop in 0..SCOOPS_PER_NONCE as usize {
+			let mirror = SCOOPS_PER_NONCE as usize - 1 - scoop;
+			for n in 0..nonces as usize {
+				let to = (scoop * nonces as usize + n) * SCOOP_SIZE;
+				let from = n * NONCE_SIZE;
+				plot[to..to + 32].copy_from_slice(&cache[from + scoop * SCOOP_SIZE..from + scoop * SCOOP_SIZE + 32]);
+				plot[to + 32..to + 64].copy_from_slice(&cache[from + mirror * SCOOP_SIZE + 32..from + mirror * SCOOP_SIZE + 64]);
+			}
+		}
+		fs::write(&path, &plot).unwrap();
+
+		let reader = PlotReader::open(&path).unwrap();
+		let owner = account_id_bytes(account_id);
+		assert_eq!(reader.verify_plot(&owner, (5, 8)), Ok(()));
+		assert!(reader.verify_plot(&account_id_bytes(1), (5, 8)).is_err());
+		assert!(reader.verify_plot(&owner, (4, 8)).is_err());
+
+		// Corrupt the last scoop of the last nonce.
+		let last = plot.len() - 1;
+		plot[last] ^= 1;
+		fs::write(&path, &plot).unwrap();
+		assert!(reader.verify_plot(&owner, (5, 8)).unwrap_err().contains("corrupt"));
+		assert_eq!(reader.verify_plot(&owner, (5, 7)), Ok(()));
+
+		fs::remove_dir_all(&dir).unwrap();
+	}
 }
@@ -17,6 +17,8 @@ pub struct CustomArgs {
 	threads: Option<usize>,
 	#[structopt(long)]
 	round: Option<u32>,
+	#[structopt(long, parse(from_os_str))]
+	plot_file: Option<std::path::PathBuf>,
 }
 
 impl_augment_clap!(CustomArgs);
@@ -51,6 +53,7 @@ pub fn run<I, T, E>(args: I, exit: E, version: VersionInfo) -> error::Result<()>
 						custom_args.author.as_ref().map(|s| s.as_str()),
 						custom_args.threads.unwrap_or(1),
 						custom_args.round.unwrap_or(5000),
+						custom_args.plot_file.clone(),
 					).map_err(|e| format!("{:?}", e))?,
 					exit
 				),
@@ -88,11 +88,13 @@ macro_rules! new_full_start {
 			.with_import_queue(|_config, client, select_chain, _transaction_pool| {
 				let import_queue = consensus_poc::import_queue(
 					Box::new(client.clone()),
+					None,
+					None,
 					client.clone(),
 					conjugatepoc_poc::RandomXAlgorithm::new(client.clone()),
-					0,
 					select_chain,
 					inherent_data_providers.clone(),
+					consensus_poc::PocConfig::default(),
 				)?;
 				Ok(import_queue)
 			})?;
@@ -102,8 +104,13 @@ macro_rules! new_full_start {
 }
 
 /// Builds a new service for a full client.
-pub fn new_full<C: Send + Default + 'static>(config: Configuration<C, GenesisConfig>, author: Option<&str>, threads: usize, round: u32)
-	-> Result<impl AbstractService, ServiceError>
+pub fn new_full<C: Send + Default + 'static>(
+	config: Configuration<C, GenesisConfig>,
+	author: Option<&str>,
+	threads: usize,
+	round: u32,
+	plot_file: Option<std::path::PathBuf>,
+) -> Result<impl AbstractService, ServiceError>
 {
 	let is_authority = config.roles.is_authority();
 
@@ -123,18 +130,27 @@ pub fn new_full<C: Send + Default + 'static>(config: Configuration<C, GenesisCon
 				transaction_pool: service.transaction_pool(),
 			};
 
+			let mut algorithm = conjugatepoc_poc::RandomXAlgorithm::new(service.client());
+			if let Some(plot_file) = &plot_file {
+				algorithm = algorithm.with_plot_file(plot_file);
+			}
+
 			consensus_poc::start_mine(
 				Box::new(service.client().clone()),
 				service.client(),
-				conjugatepoc_poc::RandomXAlgorithm::new(service.client()),
+				algorithm,
 				proposer,
-				None,
-				round,
 				service.network(),
-				std::time::Duration::new(2, 0),
 				service.select_chain().map(|v| v.clone()),
 				inherent_data_providers.clone(),
-			);
+				consensus_poc::PocConfig {
+					round,
+					build_time: std::time::Duration::new(2, 0),
+					..Default::default()
+				},
+				None,
+				None,
+			)?;
 		}
 	}
 
@@ -158,11 +174,16 @@ pub fn new_light<C: Send + Default + 'static>(config: Configuration<C, GenesisCo
 			let fprb = Box::new(DummyFinalityProofRequestBuilder::default()) as Box<_>;
 			let import_queue = consensus_poc::import_queue(
 				Box::new(client.clone()),
+				None,
+				None,
 				client.clone(),
 				conjugatepoc_poc::RandomXAlgorithm::new(client.clone()),
-				0,
 				select_chain,
 				inherent_data_providers.clone(),
+				consensus_poc::PocConfig {
+					require_body: false,
+					..Default::default()
+				},
 			)?;
 
 			Ok((import_queue, fprb))